@@ -0,0 +1,199 @@
+use crate::table::Table;
+
+/// A monoid over `Value`, used to combine partial results in a [`ColumnIndex`].
+///
+/// Implementations need only be associative; `fold` never relies on
+/// commutativity, so non-commutative combinators (e.g. matrix products)
+/// are valid as long as range endpoints are combined in order.
+pub trait Ops {
+    type Value;
+
+    fn op(a: &Self::Value, b: &Self::Value) -> Self::Value;
+    fn identity() -> Self::Value;
+}
+
+/// An array-backed segment tree over a snapshot of a [`Table`] column.
+///
+/// Leaves live in `[n, 2n)` and internal node `i` holds `op(2i, 2i+1)`,
+/// so both `fold` and `update` run in `O(log n)`. The index owns a copy
+/// of the column's data and is independent of the table's [`Order`](crate::table::Order);
+/// it does not observe later mutations to the table.
+pub struct ColumnIndex<O: Ops> {
+    tree: Vec<O::Value>,
+    n: usize,
+}
+
+impl<O: Ops> ColumnIndex<O>
+where
+    O::Value: Clone,
+{
+    pub fn new(values: &[O::Value]) -> Self {
+        let n = values.len();
+        let mut tree = vec![O::identity(); 2 * n];
+        tree[n..2 * n].clone_from_slice(values);
+        for i in (1..n).rev() {
+            tree[i] = O::op(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        Self { tree, n }
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Combines all values in `[start, end)`, returning `O::identity()` for
+    /// an empty or inverted range.
+    pub fn fold(&self, start: usize, end: usize) -> O::Value {
+        if start >= end || end > self.n {
+            return O::identity();
+        }
+        let mut lo = start + self.n;
+        let mut hi = end + self.n;
+        let mut left = O::identity();
+        let mut right = O::identity();
+        while lo < hi {
+            if lo & 1 == 1 {
+                left = O::op(&left, &self.tree[lo]);
+                lo += 1;
+            }
+            if hi & 1 == 1 {
+                hi -= 1;
+                right = O::op(&self.tree[hi], &right);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+        O::op(&left, &right)
+    }
+
+    /// Sets leaf `i` to `value` and re-folds its ancestors. Returns `None`
+    /// if `i` is out of bounds.
+    pub fn update(&mut self, i: usize, value: O::Value) -> Option<()> {
+        if i >= self.n {
+            return None;
+        }
+        let mut pos = i + self.n;
+        self.tree[pos] = value;
+        pos /= 2;
+        while pos >= 1 {
+            self.tree[pos] = O::op(&self.tree[2 * pos], &self.tree[2 * pos + 1]);
+            pos /= 2;
+        }
+        Some(())
+    }
+}
+
+impl<T> Table<T>
+where
+    T: Default + Clone + Copy,
+{
+    /// Builds a [`ColumnIndex`] over `col` by copying it through [`Table::iter_col`],
+    /// so the index is independent of the table's storage [`Order`](crate::table::Order).
+    /// Returns `None` if `col` is out of bounds.
+    pub fn index_col<O: Ops<Value = T>>(&self, col: usize) -> Option<ColumnIndex<O>>
+    where
+        O::Value: Clone,
+    {
+        let values: Vec<T> = self.iter_col(col)?.copied().collect();
+        Some(ColumnIndex::new(&values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Order;
+
+    struct Sum;
+
+    impl Ops for Sum {
+        type Value = f32;
+
+        fn op(a: &f32, b: &f32) -> f32 {
+            a + b
+        }
+
+        fn identity() -> f32 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn index_col_matches_iter_col_regardless_of_order() {
+        for order in [Order::RowMajor, Order::ColumnMajor] {
+            let table = Table::from_vec_with_order(
+                vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                2,
+                vec!["a".to_string(), "b".to_string()],
+                order,
+            )
+            .unwrap();
+
+            let expected: f32 = table.iter_col(1).unwrap().sum();
+            let index: ColumnIndex<Sum> = table.index_col(1).unwrap();
+            assert_eq!(index.fold(0, index.len()), expected);
+        }
+    }
+
+    #[test]
+    fn index_col_out_of_bounds_is_none() {
+        let table = Table::from_vec(vec![1.0, 2.0], 1, vec!["a".to_string()]).unwrap();
+        assert!(table.index_col::<Sum>(5).is_none());
+    }
+
+    /// Non-commutative monoid (string concatenation) used to check that
+    /// `fold` combines partial results in range order rather than relying
+    /// on commutativity.
+    struct Concat;
+
+    impl Ops for Concat {
+        type Value = String;
+
+        fn op(a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+
+        fn identity() -> String {
+            String::new()
+        }
+    }
+
+    fn labels(n: usize) -> Vec<String> {
+        (0..n)
+            .map(|i| ((b'a' + i as u8) as char).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn fold_preserves_order_for_non_power_of_two_len() {
+        let index: ColumnIndex<Concat> = ColumnIndex::new(&labels(5));
+        assert_eq!(index.fold(0, 5), "abcde");
+        assert_eq!(index.fold(1, 4), "bcd");
+    }
+
+    #[test]
+    fn fold_preserves_order_for_len_seven() {
+        let index: ColumnIndex<Concat> = ColumnIndex::new(&labels(7));
+        assert_eq!(index.fold(0, 7), "abcdefg");
+        assert_eq!(index.fold(3, 6), "def");
+    }
+
+    #[test]
+    fn fold_empty_or_inverted_range_is_identity() {
+        let index: ColumnIndex<Concat> = ColumnIndex::new(&labels(5));
+        assert_eq!(index.fold(2, 2), "");
+        assert_eq!(index.fold(4, 1), "");
+        assert_eq!(index.fold(0, 0), "");
+    }
+
+    #[test]
+    fn update_preserves_order() {
+        let mut index: ColumnIndex<Concat> = ColumnIndex::new(&labels(5));
+        index.update(2, "Z".to_string());
+        assert_eq!(index.fold(0, 5), "abZde");
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use crate::point::Point;
+use crate::table::Table;
+
+/// Behaviour for `query_frame`s outside the known breakpoint range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    /// Return the value at the nearest endpoint breakpoint.
+    Clamp,
+    /// Continue the line through the two nearest breakpoints.
+    Extrapolate,
+}
+
+/// A value that can be linearly interpolated between two endpoints.
+pub trait Lerp: Copy {
+    fn lerp(a: Self, b: Self, t: f64) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(a: Self, b: Self, t: f64) -> Self {
+        a + (b - a) * t as f32
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(a: Self, b: Self, t: f64) -> Self {
+        a + (b - a) * t
+    }
+}
+
+/// Evaluates a sparse series of `(frame, value)` breakpoints at `query_frame`.
+///
+/// Finds the predecessor and successor of `query_frame` via
+/// `range(..=x).next_back()` / `range(x..).next()` and linearly
+/// interpolates between them (exact passthrough when `query_frame` matches
+/// a breakpoint). `edge` controls behaviour for queries outside the
+/// breakpoint range: `Clamp` returns the nearest endpoint's value,
+/// `Extrapolate` continues the line through the two nearest breakpoints.
+/// Returns `None` if `breakpoints` is empty.
+pub fn interpolate<T: Lerp>(
+    breakpoints: &BTreeMap<usize, T>,
+    query_frame: usize,
+    edge: Edge,
+) -> Option<T> {
+    if let Some(&exact) = breakpoints.get(&query_frame) {
+        return Some(exact);
+    }
+
+    let before = breakpoints.range(..=query_frame).next_back();
+    let after = breakpoints.range(query_frame..).next();
+
+    match (before, after) {
+        (Some((&x0, &y0)), Some((&x1, &y1))) => {
+            let t = (query_frame - x0) as f64 / (x1 - x0) as f64;
+            Some(T::lerp(y0, y1, t))
+        }
+        (None, Some((&x0, &y0))) => match edge {
+            Edge::Clamp => Some(y0),
+            Edge::Extrapolate => match breakpoints.range((x0 + 1)..).next() {
+                Some((&x1, &y1)) => {
+                    let t = (query_frame as f64 - x0 as f64) / (x1 - x0) as f64;
+                    Some(T::lerp(y0, y1, t))
+                }
+                None => Some(y0),
+            },
+        },
+        (Some((&x1, &y1)), None) => match edge {
+            Edge::Clamp => Some(y1),
+            Edge::Extrapolate => match breakpoints.range(..x1).next_back() {
+                Some((&x0, &y0)) => {
+                    let t = (query_frame as f64 - x0 as f64) / (x1 - x0) as f64;
+                    Some(T::lerp(y0, y1, t))
+                }
+                None => Some(y1),
+            },
+        },
+        (None, None) => None,
+    }
+}
+
+impl<T> Table<Option<T>>
+where
+    T: Copy + Lerp,
+{
+    /// The column's known (non-gap) samples keyed by row (frame) index.
+    /// A `None` entry marks a dropped frame / occluded marker and is
+    /// excluded from the breakpoint series, which is what lets
+    /// [`interpolate_col`](Self::interpolate_col) fill it back in.
+    pub fn col_breakpoints(&self, col: usize) -> Option<BTreeMap<usize, T>> {
+        Some(
+            self.iter_col(col)?
+                .enumerate()
+                .filter_map(|(i, v)| v.map(|value| (i, value)))
+                .collect(),
+        )
+    }
+
+    /// Interpolates column `col` at `query_frame`, densifying over any
+    /// `None` (gap) rows using their surrounding known samples.
+    pub fn interpolate_col(&self, col: usize, query_frame: usize, edge: Edge) -> Option<T> {
+        let breakpoints = self.col_breakpoints(col)?;
+        interpolate(&breakpoints, query_frame, edge)
+    }
+
+    /// Fills a new column of `count` samples at frames
+    /// `start, start + step, start + 2*step, ...`, densifying the sparse
+    /// column `col` onto a uniform frame grid.
+    pub fn resample(
+        &self,
+        col: usize,
+        start: usize,
+        step: usize,
+        count: usize,
+        edge: Edge,
+    ) -> Option<Vec<T>> {
+        let breakpoints = self.col_breakpoints(col)?;
+        (0..count)
+            .map(|i| interpolate(&breakpoints, start + i * step, edge))
+            .collect()
+    }
+}
+
+impl Table<Option<f32>> {
+    /// Interpolates a marker trajectory stored as three sparse columns
+    /// (`cols = [x, y, z]`) at `query_frame`, lerping each coordinate
+    /// independently over its own gaps so the whole point is gap-filled
+    /// in one call.
+    pub fn interpolate_point(&self, cols: [usize; 3], query_frame: usize, edge: Edge) -> Option<Point> {
+        let [cx, cy, cz] = cols;
+        Some(Point::new(
+            self.interpolate_col(cx, query_frame, edge)?,
+            self.interpolate_col(cy, query_frame, edge)?,
+            self.interpolate_col(cz, query_frame, edge)?,
+        ))
+    }
+}
+
+/// Interpolates a marker trajectory given independently as x/y/z
+/// breakpoint series, lerping each coordinate independently.
+pub fn interpolate_points(
+    x: &BTreeMap<usize, f32>,
+    y: &BTreeMap<usize, f32>,
+    z: &BTreeMap<usize, f32>,
+    query_frame: usize,
+    edge: Edge,
+) -> Option<Point> {
+    Some(Point::new(
+        interpolate(x, query_frame, edge)?,
+        interpolate(y, query_frame, edge)?,
+        interpolate(z, query_frame, edge)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakpoints(pairs: &[(usize, f32)]) -> BTreeMap<usize, f32> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn interpolate_passes_through_exact_breakpoints() {
+        let bp = breakpoints(&[(0, 10.0), (4, 50.0)]);
+        assert_eq!(interpolate(&bp, 0, Edge::Clamp), Some(10.0));
+        assert_eq!(interpolate(&bp, 4, Edge::Extrapolate), Some(50.0));
+    }
+
+    #[test]
+    fn interpolate_fills_interior_gap() {
+        let bp = breakpoints(&[(0, 10.0), (4, 50.0)]);
+        assert_eq!(interpolate(&bp, 2, Edge::Clamp), Some(30.0));
+    }
+
+    #[test]
+    fn interpolate_clamp_uses_nearest_endpoint_outside_range() {
+        let bp = breakpoints(&[(2, 10.0), (4, 50.0)]);
+        assert_eq!(interpolate(&bp, 0, Edge::Clamp), Some(10.0));
+        assert_eq!(interpolate(&bp, 6, Edge::Clamp), Some(50.0));
+    }
+
+    #[test]
+    fn interpolate_extrapolate_with_only_one_breakpoint_falls_back_to_it() {
+        let bp = breakpoints(&[(3, 7.0)]);
+        assert_eq!(interpolate(&bp, 0, Edge::Extrapolate), Some(7.0));
+        assert_eq!(interpolate(&bp, 10, Edge::Extrapolate), Some(7.0));
+    }
+
+    #[test]
+    fn interpolate_extrapolate_continues_the_line_through_two_nearest() {
+        let bp = breakpoints(&[(5, 0.0), (6, 10.0), (7, 20.0)]);
+        assert_eq!(interpolate(&bp, 3, Edge::Extrapolate), Some(-20.0));
+
+        let bp = breakpoints(&[(0, 0.0), (1, 10.0), (2, 20.0)]);
+        assert_eq!(interpolate(&bp, 5, Edge::Extrapolate), Some(50.0));
+    }
+
+    #[test]
+    fn interpolate_empty_breakpoints_is_none() {
+        let bp: BTreeMap<usize, f32> = BTreeMap::new();
+        assert_eq!(interpolate(&bp, 0, Edge::Clamp), None);
+    }
+
+    #[test]
+    fn table_interpolate_col_densifies_gaps() {
+        let data = vec![Some(10.0f32), None, None, None, Some(50.0f32)];
+        let table = Table::from_vec(data, 1, vec!["y".to_string()]).unwrap();
+        assert_eq!(table.interpolate_col(0, 2, Edge::Clamp), Some(30.0));
+        assert_eq!(table.interpolate_col(0, 0, Edge::Clamp), Some(10.0));
+    }
+
+    #[test]
+    fn table_interpolate_col_empty_column_is_none() {
+        let data: Vec<Option<f32>> = vec![];
+        let table = Table::from_vec(data, 1, vec!["y".to_string()]).unwrap();
+        assert_eq!(table.interpolate_col(0, 0, Edge::Clamp), None);
+    }
+
+    #[test]
+    fn table_resample_fills_uniform_grid() {
+        let data = vec![Some(0.0f32), None, Some(10.0f32)];
+        let table = Table::from_vec(data, 1, vec!["y".to_string()]).unwrap();
+        let resampled = table.resample(0, 0, 1, 3, Edge::Clamp).unwrap();
+        assert_eq!(resampled, vec![0.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    fn table_interpolate_point_lerps_each_coordinate_over_its_own_gaps() {
+        use crate::table::Order;
+
+        let mut data = vec![Some(0.0f32), None, Some(2.0f32)]; // x
+        data.extend(vec![Some(0.0f32), Some(4.0f32), None]); // y
+        data.extend(vec![Some(5.0f32); 3]); // z
+        let table = Table::from_vec_with_order(
+            data,
+            3,
+            vec!["x".to_string(), "y".to_string(), "z".to_string()],
+            Order::ColumnMajor,
+        )
+        .unwrap();
+
+        let point = table.interpolate_point([0, 1, 2], 1, Edge::Clamp).unwrap();
+        assert_eq!(point, Point::new(1.0, 4.0, 5.0));
+    }
+}
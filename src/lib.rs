@@ -5,10 +5,30 @@ pub mod point;
 #[path = "table.rs"]
 pub mod table;
 
+#[path = "index.rs"]
+pub mod index;
+
+#[path = "tensor.rs"]
+pub mod tensor;
+
+#[path = "interpolate.rs"]
+pub mod interpolate;
+
+#[path = "skeleton.rs"]
+pub mod skeleton;
+
 pub use point::Point;
 pub use table::Table;
+pub use index::{ColumnIndex, Ops};
+pub use tensor::Tensor;
+pub use interpolate::{Edge, Lerp};
+pub use skeleton::Skeleton;
 
 pub mod prelude {
     pub use crate::point::Point;
     pub use crate::table::Table;
+    pub use crate::index::{ColumnIndex, Ops};
+    pub use crate::tensor::Tensor;
+    pub use crate::interpolate::{Edge, Lerp};
+    pub use crate::skeleton::Skeleton;
 }
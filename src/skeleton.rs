@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use crate::point::Point;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkeletonError {
+    InvalidNumberOfPoints,
+    ParentIndexOutOfRange,
+    CyclicParents,
+}
+
+impl Error for SkeletonError {}
+impl fmt::Display for SkeletonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SkeletonError: {:?}", self)
+    }
+}
+
+/// A kinematic tree of joints (e.g. pelvis → femur → tibia → foot), each
+/// with a parent index and an associated [`Point`].
+///
+/// Ancestor relationships are precomputed with binary lifting so
+/// [`lca`](Skeleton::lca) answers in `O(log n)` rather than walking parent
+/// pointers one joint at a time. [`path`](Skeleton::path) and
+/// [`bone_length_along`](Skeleton::bone_length_along) find the LCA that way
+/// and then walk parent pointers from each endpoint up to it, so they take
+/// time proportional to the length of the path.
+pub struct Skeleton {
+    parent: Vec<usize>,
+    points: Vec<Point>,
+    depth: Vec<usize>,
+    up: Vec<Vec<usize>>,
+}
+
+impl Skeleton {
+    /// Walks parent pointers from every joint to its self-loop root,
+    /// tracking the nodes visited in the current walk so a cycle that
+    /// never reaches a root is reported as an error instead of recursing
+    /// (or looping) forever.
+    fn compute_depths(parent: &[usize]) -> Result<Vec<usize>, SkeletonError> {
+        let n = parent.len();
+        let mut depth: Vec<Option<usize>> = vec![None; n];
+
+        for start in 0..n {
+            if depth[start].is_some() {
+                continue;
+            }
+
+            let mut path = Vec::new();
+            let mut visiting = HashSet::new();
+            let mut v = start;
+            let base_depth = loop {
+                if let Some(d) = depth[v] {
+                    break d;
+                }
+                if parent[v] == v {
+                    depth[v] = Some(0);
+                    break 0;
+                }
+                if !visiting.insert(v) {
+                    return Err(SkeletonError::CyclicParents);
+                }
+                path.push(v);
+                v = parent[v];
+            };
+
+            for (i, &node) in path.iter().rev().enumerate() {
+                depth[node] = Some(base_depth + i + 1);
+            }
+        }
+
+        Ok(depth.into_iter().map(|d| d.unwrap()).collect())
+    }
+
+    /// Builds a skeleton from `parent[v]`, the parent joint of `v` (the
+    /// root points to itself), and the matching `points`.
+    ///
+    /// Returns `Err(SkeletonError::InvalidNumberOfPoints)` if
+    /// `parent.len() != points.len()`, `Err(SkeletonError::ParentIndexOutOfRange)`
+    /// if any `parent[v] >= parent.len()`, and `Err(SkeletonError::CyclicParents)`
+    /// if following parent pointers from any joint never reaches a
+    /// self-loop root.
+    pub fn new(parent: Vec<usize>, points: Vec<Point>) -> Result<Self, SkeletonError> {
+        if parent.len() != points.len() {
+            return Err(SkeletonError::InvalidNumberOfPoints);
+        }
+        let n = parent.len();
+        if parent.iter().any(|&p| p >= n) {
+            return Err(SkeletonError::ParentIndexOutOfRange);
+        }
+
+        let depth = Self::compute_depths(&parent)?;
+
+        let levels = if n <= 1 {
+            1
+        } else {
+            (usize::BITS - (n - 1).leading_zeros()) as usize
+        };
+        let mut up = vec![vec![0usize; n]; levels];
+        up[0] = parent.clone();
+        for k in 1..levels {
+            for v in 0..n {
+                up[k][v] = up[k - 1][up[k - 1][v]];
+            }
+        }
+
+        Ok(Self {
+            parent,
+            points,
+            depth,
+            up,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn parent_of(&self, v: usize) -> Option<usize> {
+        self.parent.get(v).copied()
+    }
+
+    pub fn depth_of(&self, v: usize) -> Option<usize> {
+        self.depth.get(v).copied()
+    }
+
+    pub fn point(&self, v: usize) -> Option<Point> {
+        self.points.get(v).copied()
+    }
+
+    fn lift(&self, mut v: usize, mut steps: usize) -> usize {
+        let mut k = 0;
+        while steps > 0 {
+            if steps & 1 == 1 {
+                v = self.up[k][v];
+            }
+            steps >>= 1;
+            k += 1;
+        }
+        v
+    }
+
+    /// The lowest common ancestor of `i` and `j`: lifts the deeper node to
+    /// equal depth, then lifts both in lockstep from the highest power of
+    /// two down until their parents coincide. Returns `None` if either
+    /// joint index is out of bounds.
+    pub fn lca(&self, i: usize, j: usize) -> Option<usize> {
+        if i >= self.len() || j >= self.len() {
+            return None;
+        }
+        let (mut a, mut b) = (i, j);
+        if self.depth[a] < self.depth[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        a = self.lift(a, self.depth[a] - self.depth[b]);
+        if a == b {
+            return Some(a);
+        }
+        for k in (0..self.up.len()).rev() {
+            if self.up[k][a] != self.up[k][b] {
+                a = self.up[k][a];
+                b = self.up[k][b];
+            }
+        }
+        Some(self.up[0][a])
+    }
+
+    /// The ordered joint indices from `i` up to the LCA of `i` and `j`, and
+    /// back down to `j`. Returns `None` if either joint index is out of
+    /// bounds.
+    pub fn path(&self, i: usize, j: usize) -> Option<Vec<usize>> {
+        let anchor = self.lca(i, j)?;
+
+        let mut up_part = Vec::new();
+        let mut v = i;
+        while v != anchor {
+            up_part.push(v);
+            v = self.parent[v];
+        }
+        up_part.push(anchor);
+
+        let mut down_part = Vec::new();
+        let mut v = j;
+        while v != anchor {
+            down_part.push(v);
+            v = self.parent[v];
+        }
+        down_part.reverse();
+
+        up_part.extend(down_part);
+        Some(up_part)
+    }
+
+    /// Sums [`Point::distance`] over consecutive joints on `path(i, j)`,
+    /// giving the limb length (or accumulated transform distance) through
+    /// that chain. Returns `None` if either joint index is out of bounds.
+    pub fn bone_length_along(&self, i: usize, j: usize) -> Option<f32> {
+        let path = self.path(i, j)?;
+        Some(
+            path.windows(2)
+                .map(|pair| self.points[pair[0]].distance(&self.points[pair[1]]))
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// pelvis(0) -> femur(1) -> {tibia(2), other_femur(3)} -> foot(4) under tibia.
+    fn sample() -> Skeleton {
+        let parent = vec![0, 0, 1, 1, 2];
+        let points = (0..parent.len())
+            .map(|i| Point::new(i as f32, 0.0, 0.0))
+            .collect();
+        Skeleton::new(parent, points).unwrap()
+    }
+
+    #[test]
+    fn root_is_its_own_parent_with_depth_zero() {
+        let skeleton = sample();
+        assert_eq!(skeleton.parent_of(0), Some(0));
+        assert_eq!(skeleton.depth_of(0), Some(0));
+        assert_eq!(skeleton.depth_of(4), Some(3));
+    }
+
+    #[test]
+    fn lca_of_a_joint_with_itself_is_itself() {
+        let skeleton = sample();
+        for v in 0..skeleton.len() {
+            assert_eq!(skeleton.lca(v, v), Some(v));
+        }
+    }
+
+    #[test]
+    fn lca_and_path_cross_branches_through_common_ancestor() {
+        let skeleton = sample();
+        assert_eq!(skeleton.lca(4, 3), Some(1));
+        assert_eq!(skeleton.path(4, 3), Some(vec![4, 2, 1, 3]));
+    }
+
+    #[test]
+    fn out_of_range_lifts_saturate_at_root() {
+        let skeleton = sample();
+        assert_eq!(skeleton.lca(0, 4), Some(0));
+        // Joint 4 has depth 3, so lifting it by 4 overshoots the root.
+        assert_eq!(skeleton.lift(4, 4), 0);
+    }
+
+    #[test]
+    fn bone_length_along_sums_distance_over_the_path() {
+        let skeleton = sample();
+        assert_eq!(skeleton.bone_length_along(4, 3), Some(5.0));
+    }
+
+    #[test]
+    fn out_of_bounds_joint_queries_are_none() {
+        let skeleton = sample();
+        assert_eq!(skeleton.parent_of(10), None);
+        assert_eq!(skeleton.depth_of(10), None);
+        assert_eq!(skeleton.point(10), None);
+        assert_eq!(skeleton.lca(10, 0), None);
+        assert_eq!(skeleton.path(10, 0), None);
+        assert_eq!(skeleton.bone_length_along(10, 0), None);
+    }
+
+    #[test]
+    fn new_rejects_mismatched_lengths() {
+        match Skeleton::new(vec![0, 0], vec![Point::default()]) {
+            Err(SkeletonError::InvalidNumberOfPoints) => {}
+            _ => panic!("expected InvalidNumberOfPoints"),
+        }
+    }
+
+    #[test]
+    fn new_rejects_out_of_range_parent_index() {
+        let points = vec![Point::default(); 3];
+        match Skeleton::new(vec![5, 0, 1], points) {
+            Err(SkeletonError::ParentIndexOutOfRange) => {}
+            _ => panic!("expected ParentIndexOutOfRange"),
+        }
+    }
+
+    #[test]
+    fn new_rejects_cyclic_parents_without_a_self_loop_root() {
+        let points = vec![Point::default(); 2];
+        match Skeleton::new(vec![1, 0], points) {
+            Err(SkeletonError::CyclicParents) => {}
+            _ => panic!("expected CyclicParents"),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TensorOrder {
+    #[default]
+    RowMajor,
+    ColumnMajor,
+}
+
+/// The bounds of a single axis, tracked independently of storage so the
+/// axis can grow in either direction without moving already-placed data
+/// until a re-layout is actually needed (see [`Tensor::include`] and
+/// [`Tensor::extend`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct AxisBounds {
+    offset: isize,
+    size: usize,
+}
+
+/// A growable N-dimensional array for frame × marker × coordinate data.
+///
+/// Unlike [`Table`](crate::table::Table), which is fixed at two axes,
+/// `Tensor` carries an arbitrary `shape` and matching `strides`, and lets
+/// axes grow on demand: [`include`](Tensor::include) widens an axis to
+/// cover a newly seen coordinate, and [`extend`](Tensor::extend) pads
+/// every axis by one element on both ends, the way a Conway-style field
+/// grows its backing store.
+pub struct Tensor<T> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    bounds: Vec<AxisBounds>,
+    order: TensorOrder,
+}
+
+fn strides_for(shape: &[usize], order: TensorOrder) -> Vec<usize> {
+    let n = shape.len();
+    let mut strides = vec![1usize; n];
+    match order {
+        TensorOrder::RowMajor => {
+            for i in (0..n.saturating_sub(1)).rev() {
+                strides[i] = strides[i + 1] * shape[i + 1];
+            }
+        }
+        TensorOrder::ColumnMajor => {
+            for i in 1..n {
+                strides[i] = strides[i - 1] * shape[i - 1];
+            }
+        }
+    }
+    strides
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + Clone + Copy,
+{
+    pub fn new(shape: Vec<usize>) -> Self {
+        Self::new_with_order(shape, TensorOrder::RowMajor)
+    }
+
+    pub fn new_with_order(shape: Vec<usize>, order: TensorOrder) -> Self {
+        let len = shape.iter().product();
+        let strides = strides_for(&shape, order);
+        let bounds = shape
+            .iter()
+            .map(|&size| AxisBounds { offset: 0, size })
+            .collect();
+        Self {
+            data: vec![T::default(); len],
+            shape,
+            strides,
+            bounds,
+            order,
+        }
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    pub fn order(&self) -> TensorOrder {
+        self.order
+    }
+
+    /// Converts a signed logical coordinate on `axis` to a storage index,
+    /// or `None` if `pos` falls outside the axis's current bounds.
+    pub fn map(&self, axis: usize, pos: isize) -> Option<usize> {
+        let bounds = self.bounds.get(axis)?;
+        let local = pos - bounds.offset;
+        if local < 0 || local as usize >= bounds.size {
+            return None;
+        }
+        Some(local as usize)
+    }
+
+    fn linear_index(&self, coords: &[isize]) -> Option<usize> {
+        if coords.len() != self.shape.len() {
+            return None;
+        }
+        let mut index = 0usize;
+        for (axis, &pos) in coords.iter().enumerate() {
+            index += self.map(axis, pos)? * self.strides[axis];
+        }
+        Some(index)
+    }
+
+    pub fn get(&self, coords: &[isize]) -> Option<&T> {
+        self.linear_index(coords).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, coords: &[isize]) -> Option<&mut T> {
+        self.linear_index(coords).map(move |i| &mut self.data[i])
+    }
+
+    pub fn set(&mut self, coords: &[isize], value: T) -> Option<T> {
+        let i = self.linear_index(coords)?;
+        Some(std::mem::replace(&mut self.data[i], value))
+    }
+
+    /// Widens `axis`'s offset/size to cover `pos`, re-laying out the
+    /// backing storage while preserving every element at its logical
+    /// coordinate. A no-op if `pos` is already in bounds.
+    pub fn include(&mut self, axis: usize, pos: isize) {
+        if self.map(axis, pos).is_some() {
+            return;
+        }
+        let bounds = self.bounds[axis];
+        let new_offset = bounds.offset.min(pos);
+        let new_end = (bounds.offset + bounds.size as isize).max(pos + 1);
+        let new_size = (new_end - new_offset) as usize;
+        self.relayout_axis(axis, new_offset, new_size);
+    }
+
+    /// Pads every axis by one element on both ends and re-lays-out the
+    /// backing `Vec` into the enlarged shape, preserving existing elements
+    /// at their logical coordinates.
+    pub fn extend(&mut self) {
+        for axis in 0..self.shape.len() {
+            let bounds = self.bounds[axis];
+            self.relayout_axis(axis, bounds.offset - 1, bounds.size + 2);
+        }
+    }
+
+    fn relayout_axis(&mut self, axis: usize, new_offset: isize, new_size: usize) {
+        let mut new_shape = self.shape.clone();
+        new_shape[axis] = new_size;
+        let new_strides = strides_for(&new_shape, self.order);
+        let new_len: usize = new_shape.iter().product();
+        let mut new_data = vec![T::default(); new_len];
+
+        let old_bounds = self.bounds[axis];
+        let shift = old_bounds.offset - new_offset;
+        for old_index in 0..self.data.len() {
+            let mut coords = self.unflatten(old_index);
+            coords[axis] += shift as usize;
+            let new_index: usize = coords
+                .iter()
+                .zip(new_strides.iter())
+                .map(|(c, s)| c * s)
+                .sum();
+            new_data[new_index] = self.data[old_index];
+        }
+
+        self.bounds[axis] = AxisBounds {
+            offset: new_offset,
+            size: new_size,
+        };
+        self.shape = new_shape;
+        self.strides = new_strides;
+        self.data = new_data;
+    }
+
+    /// Splits a storage index into per-axis indices, taking axes from the
+    /// largest stride down so this also holds after [`Tensor::transpose`].
+    /// On a stride tie the longer axis goes first; the other has size 1.
+    fn unflatten(&self, mut index: usize) -> Vec<usize> {
+        let mut coords = vec![0usize; self.shape.len()];
+        let mut axes: Vec<usize> = (0..self.shape.len()).collect();
+        axes.sort_by_key(|&a| std::cmp::Reverse((self.strides[a], self.shape[a])));
+        for axis in axes {
+            coords[axis] = index / self.strides[axis];
+            index %= self.strides[axis];
+        }
+        coords
+    }
+
+    /// Iterates the slice of elements along `axis` at the fixed index
+    /// `idx` on every other axis, analogous to `Table::iter_row`/`iter_col`.
+    pub fn axis_iter(&self, axis: usize, idx: &[usize]) -> Option<impl Iterator<Item = &T>> {
+        if axis >= self.shape.len() || idx.len() != self.shape.len() - 1 {
+            return None;
+        }
+        let mut coords = vec![0usize; self.shape.len()];
+        let mut other = idx.iter();
+        for (i, slot) in coords.iter_mut().enumerate() {
+            if i != axis {
+                *slot = *other.next()?;
+            }
+        }
+        for (i, &c) in coords.iter().enumerate() {
+            if i != axis && c >= self.shape[i] {
+                return None;
+            }
+        }
+        let base: usize = coords
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != axis)
+            .map(|(i, &c)| c * self.strides[i])
+            .sum();
+        let stride = self.strides[axis];
+        let len = self.shape[axis];
+        Some((0..len).map(move |i| &self.data[base + i * stride]))
+    }
+
+    /// Zero-copy reshape: reinterprets the same backing data under a new
+    /// shape of equal total length, along the tensor's current `order`.
+    pub fn reshape(&mut self, shape: Vec<usize>) -> Option<()> {
+        let new_len: usize = shape.iter().product();
+        if new_len != self.data.len() {
+            return None;
+        }
+        self.strides = strides_for(&shape, self.order);
+        self.bounds = shape
+            .iter()
+            .map(|&size| AxisBounds { offset: 0, size })
+            .collect();
+        self.shape = shape;
+        Some(())
+    }
+
+    /// Lazily transposes two axes by swapping their shape/stride/bounds
+    /// entries, mirroring `Table::transpose` — no data is moved.
+    pub fn transpose(&mut self, axis_a: usize, axis_b: usize) {
+        self.shape.swap(axis_a, axis_b);
+        self.strides.swap(axis_a, axis_b);
+        self.bounds.swap(axis_a, axis_b);
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn include_widens_axis_and_preserves_existing_elements() {
+        let mut t: Tensor<i32> = Tensor::new(vec![2, 2]);
+        t.set(&[0, 0], 1);
+        t.set(&[0, 1], 2);
+        t.set(&[1, 0], 3);
+        t.set(&[1, 1], 4);
+
+        t.include(0, 5);
+
+        assert_eq!(t.get(&[0, 0]), Some(&1));
+        assert_eq!(t.get(&[0, 1]), Some(&2));
+        assert_eq!(t.get(&[1, 0]), Some(&3));
+        assert_eq!(t.get(&[1, 1]), Some(&4));
+        assert_eq!(t.get(&[5, 0]), Some(&0));
+    }
+
+    #[test]
+    fn extend_pads_every_axis_and_preserves_existing_elements() {
+        let mut t: Tensor<i32> = Tensor::new(vec![2, 2]);
+        t.set(&[0, 0], 1);
+        t.set(&[1, 1], 4);
+
+        t.extend();
+
+        assert_eq!(t.shape(), &[4, 4]);
+        assert_eq!(t.get(&[0, 0]), Some(&1));
+        assert_eq!(t.get(&[1, 1]), Some(&4));
+        assert_eq!(t.get(&[-1, -1]), Some(&0));
+        assert_eq!(t.get(&[2, 2]), Some(&0));
+    }
+
+    #[test]
+    fn degenerate_size_one_axis_indexes_correctly() {
+        let mut t: Tensor<i32> = Tensor::new(vec![1, 3]);
+        t.set(&[0, 0], 10);
+        t.set(&[0, 1], 20);
+        t.set(&[0, 2], 30);
+
+        assert_eq!(t.get(&[0, 0]), Some(&10));
+        assert_eq!(t.get(&[0, 1]), Some(&20));
+        assert_eq!(t.get(&[0, 2]), Some(&30));
+        assert_eq!(t.get(&[1, 0]), None);
+    }
+}